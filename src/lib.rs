@@ -2,6 +2,7 @@ extern crate movecell;
 extern crate typed_arena;
 
 use std::cell::Cell;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::ops::Deref;
 use std::iter::FromIterator;
 
@@ -43,6 +44,411 @@ impl <'a, T: 'a> Graph<'a, T> {
         self.root.set(Some(root));
         root
     }
+
+    /// Partitions every node reachable from the root into strongly connected components, in a
+    /// single linear pass using Tarjan's algorithm. The walk is iterative (rather than recursive)
+    /// so it doesn't overflow the stack on deep graphs. Components are returned in reverse
+    /// topological order, a natural byproduct of the algorithm.
+    pub fn sccs(&'a self) -> Vec<Vec<&'a Node<'a, T>>> {
+        let root = self.root();
+
+        let mut index = HashMap::new();
+        let mut lowlink = HashMap::new();
+        let mut on_stack = HashSet::new();
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        // Each work frame is a node paired with a snapshot of its children and a cursor into
+        // that snapshot, standing in for the call stack of the recursive formulation.
+        let mut work: Vec<(&'a Node<'a, T>, Vec<&'a Node<'a, T>>, usize)> = Vec::new();
+
+        index.insert(node_ptr(root), next_index);
+        lowlink.insert(node_ptr(root), next_index);
+        next_index += 1;
+        stack.push(root);
+        on_stack.insert(node_ptr(root));
+        work.push((root, root.edges_snapshot(), 0));
+
+        while let Some((node, children, mut i)) = work.pop() {
+            let node_key = node_ptr(node);
+            let mut recursed = false;
+
+            while i < children.len() {
+                let child = children[i];
+                let child_key = node_ptr(child);
+                i += 1;
+
+                if !index.contains_key(&child_key) {
+                    index.insert(child_key, next_index);
+                    lowlink.insert(child_key, next_index);
+                    next_index += 1;
+                    stack.push(child);
+                    on_stack.insert(child_key);
+
+                    work.push((node, children, i));
+                    work.push((child, child.edges_snapshot(), 0));
+                    recursed = true;
+                    break;
+                } else if on_stack.contains(&child_key) {
+                    let child_index = index[&child_key];
+                    if child_index < lowlink[&node_key] {
+                        lowlink.insert(node_key, child_index);
+                    }
+                }
+            }
+
+            if recursed {
+                continue;
+            }
+
+            if lowlink[&node_key] == index[&node_key] {
+                let mut component = Vec::new();
+                loop {
+                    let popped = stack.pop().unwrap();
+                    on_stack.remove(&node_ptr(popped));
+                    component.push(popped);
+                    if node_ptr(popped) == node_key {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+
+            if let Some(&(parent, _, _)) = work.last() {
+                let parent_key = node_ptr(parent);
+                let node_low = lowlink[&node_key];
+                if node_low < lowlink[&parent_key] {
+                    lowlink.insert(parent_key, node_low);
+                }
+            }
+        }
+
+        components
+    }
+
+    /// Computes the immediate dominator of every node reachable from the root, using the
+    /// iterative Cooper-Harvey-Kennedy algorithm. A node `d` dominates `n` if every path from
+    /// the root to `n` passes through `d`; the immediate dominator is the unique closest such
+    /// node.
+    pub fn dominators(&'a self) -> Dominators<'a, T> {
+        let root = self.root();
+        let rpo = reverse_postorder(root);
+        let preds = self.reverse_index();
+
+        let mut rpo_index = HashMap::new();
+        for (i, &node) in rpo.iter().enumerate() {
+            rpo_index.insert(node_ptr(node), i);
+        }
+
+        let mut idom = HashMap::new();
+        idom.insert(node_ptr(root), root);
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for &node in rpo.iter().skip(1) {
+                let node_key = node_ptr(node);
+                let mut new_idom: Option<&'a Node<'a, T>> = None;
+
+                if let Some(node_preds) = preds.get(&node_key) {
+                    for &pred in node_preds {
+                        if !idom.contains_key(&node_ptr(pred)) {
+                            continue;
+                        }
+                        new_idom = Some(match new_idom {
+                            None => pred,
+                            Some(current) => intersect(&idom, &rpo_index, current, pred),
+                        });
+                    }
+                }
+
+                if let Some(new_idom) = new_idom {
+                    let is_changed = match idom.get(&node_key) {
+                        Some(&existing) => node_ptr(existing) != node_ptr(new_idom),
+                        None => true,
+                    };
+                    if is_changed {
+                        idom.insert(node_key, new_idom);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        Dominators {
+            root: root,
+            idom: idom,
+        }
+    }
+
+    /// Maps every node reachable from the root to the nodes holding an outgoing edge to it.
+    /// `Node` only stores its own out-edges, so this is built by sweeping every node's edges
+    /// once; it's what makes backward questions ("who points at me") and the `ReversedDfs` /
+    /// `ReversedBfs` adapters possible without mutating `Node` or changing how graphs are
+    /// constructed top-down via `add_edge`. A node with no predecessors (normally the root) has
+    /// no entry in the map.
+    pub fn reverse_index(&'a self) -> HashMap<*const Node<'a, T>, Vec<&'a Node<'a, T>>> {
+        let root = self.root();
+        let mut index: HashMap<*const Node<'a, T>, Vec<&'a Node<'a, T>>> = HashMap::new();
+        let mut seen = HashSet::new();
+        let mut stack = vec![root];
+        seen.insert(node_ptr(root));
+
+        while let Some(node) = stack.pop() {
+            for child in node.edges_snapshot() {
+                index.entry(node_ptr(child)).or_insert_with(Vec::new).push(node);
+                if seen.insert(node_ptr(child)) {
+                    stack.push(child);
+                }
+            }
+        }
+
+        index
+    }
+
+    /// Checks whether `self` and `other` are isomorphic: whether there's a bijection between
+    /// their reachable nodes that preserves every edge and matches node data with `T`'s
+    /// `PartialEq` impl. See `is_isomorphic_matching` to use a different equivalence.
+    pub fn is_isomorphic(&'a self, other: &'a Graph<'a, T>) -> bool where T: PartialEq {
+        self.is_isomorphic_matching(other, |a, b| a == b)
+    }
+
+    /// Like `is_isomorphic`, but compares node data with the caller-supplied `matches` closure
+    /// instead of requiring `T: PartialEq`.
+    pub fn is_isomorphic_matching<F>(&'a self, other: &'a Graph<'a, T>, matches: F) -> bool
+        where F: Fn(&T, &T) -> bool
+    {
+        let nodes_a = reachable_nodes(self.root());
+        let nodes_b = reachable_nodes(other.root());
+
+        if nodes_a.len() != nodes_b.len() {
+            return false;
+        }
+
+        let mut degrees_a: Vec<usize> = nodes_a.iter().map(|n| n.edges_snapshot().len()).collect();
+        let mut degrees_b: Vec<usize> = nodes_b.iter().map(|n| n.edges_snapshot().len()).collect();
+        degrees_a.sort();
+        degrees_b.sort();
+        if degrees_a != degrees_b {
+            return false;
+        }
+
+        let mut forward = HashMap::new();
+        let mut backward = HashMap::new();
+        vf2_match(&nodes_a, &nodes_b, 0, &mut forward, &mut backward, &matches)
+    }
+}
+
+/// Every node reachable from `root`, via forward edges, in DFS order.
+fn reachable_nodes<'a, T: 'a>(root: &'a Node<'a, T>) -> Vec<&'a Node<'a, T>> {
+    let mut nodes = Vec::new();
+    let mut seen = HashSet::new();
+    let mut stack = vec![root];
+    seen.insert(node_ptr(root));
+
+    while let Some(node) = stack.pop() {
+        nodes.push(node);
+        for child in node.edges_snapshot() {
+            if seen.insert(node_ptr(child)) {
+                stack.push(child);
+            }
+        }
+    }
+
+    nodes
+}
+
+/// VF2-style backtracking search for a bijection between `nodes_a` and `nodes_b` that preserves
+/// edges and datum equivalence. `i` is the index into `nodes_a` of the node being matched next;
+/// `forward`/`backward` hold the partial mapping built so far, keyed by node pointer since the
+/// two graphs live in separate arenas.
+fn vf2_match<'a, T: 'a, F>(
+    nodes_a: &[&'a Node<'a, T>],
+    nodes_b: &[&'a Node<'a, T>],
+    i: usize,
+    forward: &mut HashMap<*const Node<'a, T>, *const Node<'a, T>>,
+    backward: &mut HashMap<*const Node<'a, T>, *const Node<'a, T>>,
+    matches: &F,
+) -> bool
+    where F: Fn(&T, &T) -> bool
+{
+    if i == nodes_a.len() {
+        return true;
+    }
+
+    let a = nodes_a[i];
+    let a_edges = a.edges_snapshot();
+
+    for &b in nodes_b {
+        if backward.contains_key(&node_ptr(b)) {
+            continue;
+        }
+
+        let b_edges = b.edges_snapshot();
+        if a_edges.len() != b_edges.len() || !matches(&a.datum, &b.datum) {
+            continue;
+        }
+
+        if !edges_consistent(&a_edges, &b_edges, forward, backward) {
+            continue;
+        }
+
+        // `edges_consistent` only sees the out-edges of `a` and `b`; self-loops and edges into
+        // them from already-mapped nodes (exactly `nodes_a[..i]`) are checked here.
+        let points_at = |from: &'a Node<'a, T>, to: &'a Node<'a, T>| {
+            from.edges_snapshot().iter().any(|&c| node_ptr(c) == node_ptr(to))
+        };
+        if points_at(a, a) != points_at(b, b) {
+            continue;
+        }
+        let preds_consistent = nodes_a[..i].iter().all(|&x| {
+            let y = nodes_b.iter().cloned().find(|&y| node_ptr(y) == forward[&node_ptr(x)]).unwrap();
+            points_at(x, a) == points_at(y, b)
+        });
+        if !preds_consistent {
+            continue;
+        }
+
+        forward.insert(node_ptr(a), node_ptr(b));
+        backward.insert(node_ptr(b), node_ptr(a));
+
+        if vf2_match(nodes_a, nodes_b, i + 1, forward, backward, matches) {
+            return true;
+        }
+
+        forward.remove(&node_ptr(a));
+        backward.remove(&node_ptr(b));
+    }
+
+    false
+}
+
+/// Checks that matching `a` to `b` wouldn't contradict any edge already implied by the partial
+/// mapping: every already-mapped neighbor of `a` must map to a neighbor of `b`, and vice versa.
+fn edges_consistent<'a, T: 'a>(
+    a_edges: &[&'a Node<'a, T>],
+    b_edges: &[&'a Node<'a, T>],
+    forward: &HashMap<*const Node<'a, T>, *const Node<'a, T>>,
+    backward: &HashMap<*const Node<'a, T>, *const Node<'a, T>>,
+) -> bool {
+    for &child_a in a_edges {
+        if let Some(&mapped) = forward.get(&node_ptr(child_a)) {
+            if !b_edges.iter().any(|&child_b| node_ptr(child_b) == mapped) {
+                return false;
+            }
+        }
+    }
+
+    for &child_b in b_edges {
+        if let Some(&mapped) = backward.get(&node_ptr(child_b)) {
+            if !a_edges.iter().any(|&child_a| node_ptr(child_a) == mapped) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Reverse-postorder numbering of the nodes reachable from `root`, via a DFS from the root. The
+/// root is always first; a node's dominators always precede it in this order.
+fn reverse_postorder<'a, T: 'a>(root: &'a Node<'a, T>) -> Vec<&'a Node<'a, T>> {
+    let mut order = Vec::new();
+    let mut seen = HashSet::new();
+    let mut stack = vec![(root, false)];
+    seen.insert(node_ptr(root));
+
+    while let Some((node, expanded)) = stack.pop() {
+        if expanded {
+            order.push(node);
+            continue;
+        }
+        stack.push((node, true));
+        for child in node.edges_snapshot() {
+            if seen.insert(node_ptr(child)) {
+                stack.push((child, false));
+            }
+        }
+    }
+
+    order.reverse();
+    order
+}
+
+/// Walks `a` and `b` up their current `idom` pointers, repeatedly advancing whichever has the
+/// deeper reverse-postorder number, until they converge on their common dominator.
+fn intersect<'a, T: 'a>(
+    idom: &HashMap<*const Node<'a, T>, &'a Node<'a, T>>,
+    rpo_index: &HashMap<*const Node<'a, T>, usize>,
+    mut a: &'a Node<'a, T>,
+    mut b: &'a Node<'a, T>,
+) -> &'a Node<'a, T> {
+    while node_ptr(a) != node_ptr(b) {
+        while rpo_index[&node_ptr(a)] > rpo_index[&node_ptr(b)] {
+            a = idom[&node_ptr(a)];
+        }
+        while rpo_index[&node_ptr(b)] > rpo_index[&node_ptr(a)] {
+            b = idom[&node_ptr(b)];
+        }
+    }
+    a
+}
+
+/// Immediate-dominator tree computed by `Graph::dominators`.
+pub struct Dominators<'a, T: 'a> {
+    root: &'a Node<'a, T>,
+    idom: HashMap<*const Node<'a, T>, &'a Node<'a, T>>,
+}
+
+impl<'a, T: 'a> Dominators<'a, T> {
+    /// The immediate dominator of `node`, or `None` if `node` is the root (the root has no
+    /// dominator) or is unreachable.
+    pub fn immediate_dominator(&self, node: &'a Node<'a, T>) -> Option<&'a Node<'a, T>> {
+        if node_ptr(node) == node_ptr(self.root) {
+            None
+        } else {
+            self.idom.get(&node_ptr(node)).cloned()
+        }
+    }
+
+    /// Iterates the dominators of `node`, from its immediate dominator up to (and including)
+    /// the root.
+    pub fn dominators<'b>(&'b self, node: &'a Node<'a, T>) -> DominatorsIter<'b, 'a, T> {
+        DominatorsIter {
+            root_key: node_ptr(self.root),
+            idom: &self.idom,
+            current: Some(node),
+        }
+    }
+}
+
+/// Iterator over a node's dominator chain. See `Dominators::dominators`.
+pub struct DominatorsIter<'b, 'a: 'b, T: 'a> {
+    root_key: *const Node<'a, T>,
+    idom: &'b HashMap<*const Node<'a, T>, &'a Node<'a, T>>,
+    current: Option<&'a Node<'a, T>>,
+}
+
+impl<'b, 'a, T> Iterator for DominatorsIter<'b, 'a, T> {
+    type Item = &'a Node<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = match self.current {
+            Some(current) => current,
+            None => return None,
+        };
+
+        if node_ptr(current) == self.root_key {
+            self.current = None;
+            return None;
+        }
+
+        let dom = self.idom[&node_ptr(current)];
+        self.current = Some(dom);
+        Some(dom)
+    }
 }
 
 /// Most actions applied to the graph are really just applied to the root node
@@ -89,6 +495,51 @@ impl<'a, T: 'a> Node<'a, T> {
             branch_points: vec![(None, self)]
         }
     }
+
+    /// Cycle-safe depth-first iterator adapter. `dfs` walks edges blindly and will loop forever
+    /// (or revisit nodes) on a graph containing a cycle or a node reachable via more than one
+    /// path. This adapter carries a visited set keyed by node identity, so it terminates and
+    /// yields every reachable node exactly once on arbitrary directed graphs, not just trees and
+    /// DAGs.
+    pub fn dfs_unique(&'a self) -> DfsUniqueIter<'a, T> {
+        let mut visited = HashSet::new();
+        visited.insert(node_ptr(self));
+        DfsUniqueIter {
+            branch_points: vec![(None, self)],
+            visited: visited,
+        }
+    }
+
+    /// Returns a snapshot of this node's outgoing edges without disturbing the `MoveCell` used
+    /// by the top-down construction trick. Unlike the iterator adapters above, algorithms that
+    /// need to read a node's edges more than once (SCCs, dominators, isomorphism) want a cheap
+    /// read rather than a one-shot take/replace dance at each call site.
+    fn edges_snapshot(&self) -> Vec<&'a Node<'a, T>> {
+        let edges = self.edges.take().unwrap_or_default();
+        self.edges.replace(Some(edges.clone()));
+        edges
+    }
+
+    /// Iterator adapter for Breadth-first traversals of the graph, mirroring `dfs`'s structure
+    /// but walking a `VecDeque` frontier rather than a stack. Carries the same visited-set guard
+    /// as `dfs_unique` so shared or cyclic graphs don't enqueue a node twice.
+    pub fn bfs(&'a self) -> BfsIter<'a, T> {
+        let mut visited = HashSet::new();
+        visited.insert(node_ptr(self));
+        let mut frontier = VecDeque::new();
+        frontier.push_back(self);
+        BfsIter {
+            frontier: frontier,
+            visited: visited,
+        }
+    }
+}
+
+/// Stable identity for a node within its owning arena. Nodes have no `Eq`/`Hash` impl of their
+/// own (since `T` may not support it), so anywhere the graph needs "have I seen this node"
+/// bookkeeping it keys a set or map off of this pointer instead.
+fn node_ptr<'a, T: 'a>(node: &'a Node<'a, T>) -> *const Node<'a, T> {
+    node as *const Node<'a, T>
 }
 
 impl<'a, T> FromIterator<&'a Node<'a, T>> for &'a Node<'a, T> {
@@ -149,6 +600,164 @@ impl<'a, T> Iterator for DfsIter<'a, T> {
     }
 }
 
+/// Cycle-safe depth-first iterator adapter for Nodes. See `Node::dfs_unique`.
+pub struct DfsUniqueIter<'a, T: 'a> {
+    branch_points: Vec<(Option<usize>, &'a Node<'a, T>)>,
+    visited: HashSet<*const Node<'a, T>>,
+}
+
+impl<'a, T> Iterator for DfsUniqueIter<'a, T> {
+    type Item = &'a Node<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(branch_point) = self.branch_points.pop() {
+            let (index, node) = branch_point;
+
+            if let Some(edges) = node.edges.take() {
+                let found_node = match index {
+                    None => {
+                        self.branch_points.push((Some(0), node));
+                        Some(node)
+                    },
+                    Some(index) => {
+                        if index < edges.len() {
+                            self.branch_points.push((Some(index + 1), node));
+                            let child = edges[index];
+                            if self.visited.insert(node_ptr(child)) {
+                                self.branch_points.push((None, child));
+                            }
+                        }
+                        None
+                    }
+                };
+
+                node.edges.replace(Some(edges));
+                if found_node.is_some() {
+                    return found_node;
+                }
+            } else {
+                return Some(node);
+            }
+        }
+        None
+    }
+}
+
+/// Breadth-first iterator adapter for Nodes. See `Node::bfs`.
+pub struct BfsIter<'a, T: 'a> {
+    frontier: VecDeque<&'a Node<'a, T>>,
+    visited: HashSet<*const Node<'a, T>>,
+}
+
+impl<'a, T> Iterator for BfsIter<'a, T> {
+    type Item = &'a Node<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.frontier.pop_front() {
+            Some(node) => {
+                if let Some(edges) = node.edges.take() {
+                    for &child in &edges {
+                        if self.visited.insert(node_ptr(child)) {
+                            self.frontier.push_back(child);
+                        }
+                    }
+                    node.edges.replace(Some(edges));
+                }
+                Some(node)
+            },
+            None => None,
+        }
+    }
+}
+
+/// Depth-first iterator that traverses a `reverse_index` predecessor map instead of a node's
+/// forward edges, for walking the graph against edge direction.
+pub struct ReversedDfs<'r, 'a: 'r, T: 'a> {
+    index: &'r HashMap<*const Node<'a, T>, Vec<&'a Node<'a, T>>>,
+    stack: Vec<&'a Node<'a, T>>,
+    visited: HashSet<*const Node<'a, T>>,
+}
+
+impl<'r, 'a: 'r, T: 'a> ReversedDfs<'r, 'a, T> {
+    /// Starts a reversed depth-first walk from `start`, looking up predecessors in `index` (as
+    /// produced by `Graph::reverse_index`).
+    pub fn new(index: &'r HashMap<*const Node<'a, T>, Vec<&'a Node<'a, T>>>, start: &'a Node<'a, T>) -> Self {
+        let mut visited = HashSet::new();
+        visited.insert(node_ptr(start));
+        ReversedDfs {
+            index: index,
+            stack: vec![start],
+            visited: visited,
+        }
+    }
+}
+
+impl<'r, 'a, T> Iterator for ReversedDfs<'r, 'a, T> {
+    type Item = &'a Node<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = match self.stack.pop() {
+            Some(node) => node,
+            None => return None,
+        };
+
+        if let Some(preds) = self.index.get(&node_ptr(node)) {
+            for &pred in preds {
+                if self.visited.insert(node_ptr(pred)) {
+                    self.stack.push(pred);
+                }
+            }
+        }
+
+        Some(node)
+    }
+}
+
+/// Breadth-first iterator that traverses a `reverse_index` predecessor map instead of a node's
+/// forward edges, for walking the graph against edge direction.
+pub struct ReversedBfs<'r, 'a: 'r, T: 'a> {
+    index: &'r HashMap<*const Node<'a, T>, Vec<&'a Node<'a, T>>>,
+    frontier: VecDeque<&'a Node<'a, T>>,
+    visited: HashSet<*const Node<'a, T>>,
+}
+
+impl<'r, 'a: 'r, T: 'a> ReversedBfs<'r, 'a, T> {
+    /// Starts a reversed breadth-first walk from `start`, looking up predecessors in `index`
+    /// (as produced by `Graph::reverse_index`).
+    pub fn new(index: &'r HashMap<*const Node<'a, T>, Vec<&'a Node<'a, T>>>, start: &'a Node<'a, T>) -> Self {
+        let mut visited = HashSet::new();
+        visited.insert(node_ptr(start));
+        let mut frontier = VecDeque::new();
+        frontier.push_back(start);
+        ReversedBfs {
+            index: index,
+            frontier: frontier,
+            visited: visited,
+        }
+    }
+}
+
+impl<'r, 'a, T> Iterator for ReversedBfs<'r, 'a, T> {
+    type Item = &'a Node<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = match self.frontier.pop_front() {
+            Some(node) => node,
+            None => return None,
+        };
+
+        if let Some(preds) = self.index.get(&node_ptr(node)) {
+            for &pred in preds {
+                if self.visited.insert(node_ptr(pred)) {
+                    self.frontier.push_back(pred);
+                }
+            }
+        }
+
+        Some(node)
+    }
+}
+
 #[test]
 fn it_works() {
     let graph = Graph::new();
@@ -171,3 +780,110 @@ fn it_works() {
         println!("{}", node.datum);
     }
 }
+
+#[test]
+fn dfs_unique_terminates_on_cycles() {
+    let graph = Graph::new();
+    let node1 = graph.set_root(graph.own_node(Node::new(1)));
+    let node2 = node1.add_edge(graph.own_node(Node::new(2)));
+    let node3 = node1.add_edge(graph.own_node(Node::new(3)));
+    node2.add_edge(node3);
+    node3.add_edge(node1); // cycles back to the root
+
+    let visited: Vec<_> = graph.dfs_unique().map(|n| n.datum).collect();
+    assert_eq!(visited.len(), 3);
+    assert_eq!(visited[0], 1);
+}
+
+#[test]
+fn bfs_visits_level_by_level() {
+    let graph = Graph::new();
+    let node1 = graph.set_root(graph.own_node(Node::new(1)));
+    let node2 = node1.add_edge(graph.own_node(Node::new(2)));
+    let _node3 = node1.add_edge(graph.own_node(Node::new(3)));
+    let _node4 = node2.add_edge(graph.own_node(Node::new(4)));
+
+    let visited: Vec<_> = graph.bfs().map(|n| n.datum).collect();
+    assert_eq!(visited, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn sccs_groups_cycles_and_isolates_tree_nodes() {
+    let graph = Graph::new();
+    let node1 = graph.set_root(graph.own_node(Node::new(1)));
+    let node2 = node1.add_edge(graph.own_node(Node::new(2)));
+    let node3 = node1.add_edge(graph.own_node(Node::new(3)));
+    node2.add_edge(node3);
+    node3.add_edge(node2); // node2 <-> node3 form a cycle, node1 is a lone predecessor
+
+    let mut sizes: Vec<usize> = graph.sccs().iter().map(|c| c.len()).collect();
+    sizes.sort();
+    assert_eq!(sizes, vec![1, 2]);
+}
+
+#[test]
+fn dominators_of_diamond_graph() {
+    let graph = Graph::new();
+    let node1 = graph.set_root(graph.own_node(Node::new(1)));
+    let node2 = node1.add_edge(graph.own_node(Node::new(2)));
+    let node3 = node1.add_edge(graph.own_node(Node::new(3)));
+    let node4 = graph.own_node(Node::new(4));
+    node2.add_edge(node4);
+    node3.add_edge(node4);
+
+    let dominators = graph.dominators();
+    assert!(dominators.immediate_dominator(node1).is_none());
+    assert_eq!(dominators.immediate_dominator(node2).unwrap().datum, 1);
+    assert_eq!(dominators.immediate_dominator(node3).unwrap().datum, 1);
+    // node4 is reachable through both node2 and node3, so only the root dominates it.
+    assert_eq!(dominators.immediate_dominator(node4).unwrap().datum, 1);
+}
+
+#[test]
+fn reversed_traversal_walks_against_edge_direction() {
+    let graph = Graph::new();
+    let node1 = graph.set_root(graph.own_node(Node::new(1)));
+    let node2 = node1.add_edge(graph.own_node(Node::new(2)));
+    let node3 = node1.add_edge(graph.own_node(Node::new(3)));
+    let node4 = graph.own_node(Node::new(4));
+    node2.add_edge(node4);
+    node3.add_edge(node4);
+
+    let index = graph.reverse_index();
+
+    let mut dfs_from_4: Vec<_> = ReversedDfs::new(&index, node4).map(|n| n.datum).collect();
+    dfs_from_4.sort();
+    assert_eq!(dfs_from_4, vec![1, 2, 3, 4]);
+
+    let mut bfs_from_4: Vec<_> = ReversedBfs::new(&index, node4).map(|n| n.datum).collect();
+    bfs_from_4.sort();
+    assert_eq!(bfs_from_4, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn is_isomorphic_matches_relabeled_graph_and_rejects_mismatches() {
+    let graph_a = Graph::new();
+    let a1 = graph_a.set_root(graph_a.own_node(Node::new(1)));
+    let a2 = a1.add_edge(graph_a.own_node(Node::new(2)));
+    let _a3 = a1.add_edge(graph_a.own_node(Node::new(3)));
+    let _a4 = a2.add_edge(graph_a.own_node(Node::new(4)));
+
+    // Same shape, datums relabeled.
+    let graph_b = Graph::new();
+    let b1 = graph_b.set_root(graph_b.own_node(Node::new(10)));
+    let b2 = b1.add_edge(graph_b.own_node(Node::new(20)));
+    let _b3 = b1.add_edge(graph_b.own_node(Node::new(30)));
+    let _b4 = b2.add_edge(graph_b.own_node(Node::new(40)));
+
+    assert!(graph_a.is_isomorphic_matching(&graph_b, |_, _| true));
+    assert!(!graph_a.is_isomorphic(&graph_b));
+
+    // Different shape: three leaves hanging off the root instead of a chain.
+    let graph_c = Graph::new();
+    let c1 = graph_c.set_root(graph_c.own_node(Node::new(1)));
+    c1.add_edge(graph_c.own_node(Node::new(2)));
+    c1.add_edge(graph_c.own_node(Node::new(3)));
+    c1.add_edge(graph_c.own_node(Node::new(4)));
+
+    assert!(!graph_a.is_isomorphic(&graph_c));
+}